@@ -1,51 +1,22 @@
 use std::env;
 use std::process;
 
+use test_project::expr;
+
 fn main() {
     let args: Vec<String> = env::args().collect();
-    
-    if args.len() != 4 {
-        eprintln!("Usage: {} <number1> <operator> <number2>", args[0]);
-        eprintln!("Operators: +, -, *, /");
-        eprintln!("Example: {} 5 + 3", args[0]);
+
+    if args.len() != 2 {
+        eprintln!("Usage: {} <expression>", args[0]);
+        eprintln!("Example: {} \"(10 + 5) * 2 / 4 - 1\"", args[0]);
         process::exit(1);
     }
-    
-    let num1: f64 = match args[1].parse() {
-        Ok(n) => n,
-        Err(_) => {
-            eprintln!("Error: '{}' is not a valid number", args[1]);
-            process::exit(1);
-        }
-    };
-    
-    let operator = &args[2];
-    
-    let num2: f64 = match args[3].parse() {
-        Ok(n) => n,
-        Err(_) => {
-            eprintln!("Error: '{}' is not a valid number", args[3]);
-            process::exit(1);
-        }
-    };
-    
-    let result = match operator.as_str() {
-        "+" => num1 + num2,
-        "-" => num1 - num2,
-        "*" => num1 * num2,
-        "/" => {
-            if num2 == 0.0 {
-                eprintln!("Error: Division by zero!");
-                process::exit(1);
-            }
-            num1 / num2
-        }
-        _ => {
-            eprintln!("Error: Unknown operator '{}'", operator);
-            eprintln!("Supported operators: +, -, *, /");
+
+    match expr::evaluate(&args[1]) {
+        Ok(result) => println!("{} = {}", args[1], result),
+        Err(err) => {
+            eprintln!("{}", expr::diagnostic::render(&args[1], &err));
             process::exit(1);
         }
-    };
-    
-    println!("{} {} {} = {}", num1, operator, num2, result);
-} 
\ No newline at end of file
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use super::error::{Error, ErrorKind};
+use super::value::Value;
+
+/// Bindings an expression is evaluated against: built-in constants (`pi`,
+/// `e`) plus any user-defined variables, consulted whenever an identifier
+/// or `${name:-fallback}` substitution is evaluated.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    variables: HashMap<String, Value>,
+}
+
+impl Context {
+    /// An empty context with only the built-in constants available.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind `name` to `value`, shadowing a built-in constant of the same
+    /// name if one exists.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+        self.variables.insert(name.into(), value.into());
+    }
+
+    /// Look up `name`, falling back to the built-in constants.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.variables.get(name).copied().or_else(|| builtin(name))
+    }
+
+    /// The user-defined variables, excluding built-in constants.
+    pub fn variables(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.variables.iter()
+    }
+
+    /// Parse a single `NAME=VALUE` flag, as passed via `--set`.
+    pub fn parse_binding(flag: &str) -> Result<(String, f64), Error> {
+        let (name, value) = flag.split_once('=').ok_or_else(|| {
+            Error::without_span(ErrorKind::InvalidSyntax(format!(
+                "expected NAME=VALUE, got '{}'",
+                flag
+            )))
+        })?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(Error::without_span(ErrorKind::InvalidSyntax(format!(
+                "missing variable name in '{}'",
+                flag
+            ))));
+        }
+        let value = value.trim().parse::<f64>().map_err(|_| {
+            Error::without_span(ErrorKind::InvalidNumber(value.trim().to_string()))
+        })?;
+        Ok((name.to_string(), value))
+    }
+}
+
+fn builtin(name: &str) -> Option<Value> {
+    match name {
+        "pi" => Some(Value::Real(std::f64::consts::PI)),
+        "e" => Some(Value::Real(std::f64::consts::E)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::evaluate_with_context;
+
+    #[test]
+    fn builtins_are_available_without_being_set() {
+        let ctx = Context::new();
+        assert_eq!(ctx.get("pi"), Some(Value::Real(std::f64::consts::PI)));
+        assert_eq!(ctx.get("e"), Some(Value::Real(std::f64::consts::E)));
+        assert_eq!(ctx.get("nope"), None);
+    }
+
+    #[test]
+    fn set_shadows_a_builtin() {
+        let mut ctx = Context::new();
+        ctx.set("pi", 3.0);
+        assert_eq!(ctx.get("pi"), Some(Value::Real(3.0)));
+    }
+
+    #[test]
+    fn parse_binding_accepts_name_equals_value() {
+        assert_eq!(
+            Context::parse_binding("x=5").unwrap(),
+            ("x".to_string(), 5.0)
+        );
+        assert_eq!(
+            Context::parse_binding("y = 2.5").unwrap(),
+            ("y".to_string(), 2.5)
+        );
+    }
+
+    #[test]
+    fn parse_binding_rejects_malformed_flags() {
+        assert!(Context::parse_binding("no-equals-sign").is_err());
+        assert!(Context::parse_binding("=5").is_err());
+        assert!(Context::parse_binding("x=abc").is_err());
+    }
+
+    #[test]
+    fn undefined_variable_is_an_error() {
+        let ctx = Context::new();
+        assert!(matches!(
+            evaluate_with_context("y", &ctx).unwrap_err().kind,
+            ErrorKind::UndefinedVariable(name) if name == "y"
+        ));
+    }
+
+    #[test]
+    fn default_fallback_is_used_when_unbound() {
+        let ctx = Context::new();
+        assert_eq!(
+            evaluate_with_context("${y:-2}", &ctx).unwrap(),
+            Value::Real(2.0)
+        );
+    }
+
+    #[test]
+    fn default_fallback_is_ignored_when_bound() {
+        let mut ctx = Context::new();
+        ctx.set("y", 9.0);
+        assert_eq!(
+            evaluate_with_context("${y:-2}", &ctx).unwrap(),
+            Value::Real(9.0)
+        );
+    }
+}
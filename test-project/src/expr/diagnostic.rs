@@ -0,0 +1,62 @@
+//! Rustc-style rendering of [`Error`]s against the source they came from.
+
+use super::error::Error;
+
+/// Render `err` against the `source` line it was parsed from: the source
+/// line, a line of spaces followed by `^^^` under the offending span, and
+/// the message beneath.
+///
+/// If `err` carries no span (e.g. an empty expression), only the source
+/// line and message are printed.
+pub fn render(source: &str, err: &Error) -> String {
+    let mut out = String::new();
+    out.push_str(source);
+    out.push('\n');
+
+    if let Some(span) = err.span {
+        let char_count = source.chars().count();
+        let start = span.start.min(char_count);
+        let len = span.len.max(1).min(char_count.saturating_sub(start).max(1));
+        out.push_str(&" ".repeat(start));
+        out.push_str(&"^".repeat(len));
+        out.push('\n');
+    }
+
+    out.push_str("error: ");
+    out.push_str(&err.to_string());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::evaluate;
+
+    #[test]
+    fn caret_underlines_the_offending_span() {
+        let err = evaluate("1 + @").unwrap_err();
+        let rendered = render("1 + @", &err);
+        let mut lines = rendered.lines();
+        assert_eq!(lines.next(), Some("1 + @"));
+        assert_eq!(lines.next(), Some("    ^"));
+        assert!(lines.next().unwrap().starts_with("error: "));
+    }
+
+    #[test]
+    fn caret_covers_a_multi_character_span() {
+        let err = evaluate("1.2.3").unwrap_err();
+        let rendered = render("1.2.3", &err);
+        let mut lines = rendered.lines();
+        assert_eq!(lines.next(), Some("1.2.3"));
+        assert_eq!(lines.next(), Some("^^^^^"));
+    }
+
+    #[test]
+    fn no_span_omits_the_caret_line() {
+        let err = evaluate("").unwrap_err();
+        let rendered = render("", &err);
+        let mut lines = rendered.lines();
+        assert_eq!(lines.next(), Some(""));
+        assert!(lines.next().unwrap().starts_with("error: "));
+    }
+}
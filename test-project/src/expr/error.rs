@@ -0,0 +1,95 @@
+use std::fmt;
+
+use super::span::Span;
+
+/// The kind of failure that occurred while tokenizing, parsing, or
+/// evaluating an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    /// An empty expression was given.
+    EmptyExpression,
+    /// A character does not belong to any recognized token.
+    UnexpectedCharacter(char),
+    /// A numeric literal could not be parsed.
+    InvalidNumber(String),
+    /// Parentheses did not match up.
+    UnmatchedParenthesis,
+    /// A `${...}` substitution was missing its closing brace.
+    UnmatchedBrace,
+    /// The token stream is not a valid expression (e.g. two operators in a row).
+    InvalidSyntax(String),
+    /// Division by zero was attempted.
+    DivisionByZero,
+    /// An identifier had no binding in the context and no `:-fallback`.
+    UndefinedVariable(String),
+    /// A function call used a name that isn't in the registry.
+    UnknownFunction(String),
+    /// A function was called with the wrong number of arguments.
+    ArityMismatch {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A function has no result for the arguments given (e.g. `sqrt(-1)`).
+    DomainError(String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::EmptyExpression => write!(f, "expression is empty"),
+            ErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character '{}'", c),
+            ErrorKind::InvalidNumber(s) => write!(f, "invalid number '{}'", s),
+            ErrorKind::UnmatchedParenthesis => write!(f, "unmatched parenthesis"),
+            ErrorKind::UnmatchedBrace => write!(f, "unmatched '${{' in substitution"),
+            ErrorKind::InvalidSyntax(s) => write!(f, "invalid syntax: {}", s),
+            ErrorKind::DivisionByZero => write!(f, "division by zero"),
+            ErrorKind::UndefinedVariable(name) => write!(f, "undefined variable '{}'", name),
+            ErrorKind::UnknownFunction(name) => write!(f, "unknown function '{}'", name),
+            ErrorKind::ArityMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "'{}' expects {} argument{}, got {}",
+                name,
+                expected,
+                if *expected == 1 { "" } else { "s" },
+                found
+            ),
+            ErrorKind::DomainError(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// An error produced while evaluating an expression, carrying the span of
+/// source responsible so it can be rendered as a compiler-style diagnostic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub span: Option<Span>,
+}
+
+impl Error {
+    /// An error pointing at a specific span of the source.
+    pub fn new(kind: ErrorKind, span: Span) -> Self {
+        Self {
+            kind,
+            span: Some(span),
+        }
+    }
+
+    /// An error with no single offending span (e.g. an empty expression).
+    pub fn without_span(kind: ErrorKind) -> Self {
+        Self { kind, span: None }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl std::error::Error for Error {}
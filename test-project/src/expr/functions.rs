@@ -0,0 +1,108 @@
+//! The registry of named functions callable from expression syntax
+//! (`sqrt(x)`, `pow(x, y)`, ...), wired on top of [`crate::math`].
+
+use crate::math;
+use crate::math::Complex;
+
+use super::value::Value;
+
+/// How many arguments a registered function expects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Arity {
+    Unary,
+    Binary,
+}
+
+impl Arity {
+    pub(crate) fn count(self) -> usize {
+        match self {
+            Arity::Unary => 1,
+            Arity::Binary => 2,
+        }
+    }
+}
+
+/// Look up the arity of `name`, or `None` if it isn't a registered function.
+pub(crate) fn arity(name: &str) -> Option<Arity> {
+    match name {
+        "sqrt" | "abs" | "sin" | "cos" | "tan" | "ln" | "conj" => Some(Arity::Unary),
+        "log" | "pow" | "min" | "max" => Some(Arity::Binary),
+        _ => None,
+    }
+}
+
+/// Call a unary function by name. Returns `None` if the result is outside
+/// the function's domain (e.g. `sqrt` of a negative number).
+pub(crate) fn call_unary(name: &str, a: f64) -> Option<f64> {
+    let result = match name {
+        "sqrt" => math::sqrt(a),
+        "abs" => math::abs(a),
+        "sin" => math::sin(a),
+        "cos" => math::cos(a),
+        "tan" => math::tan(a),
+        "ln" => math::ln(a),
+        "conj" => a,
+        _ => return None,
+    };
+    (!result.is_nan()).then_some(result)
+}
+
+/// Call a unary function on a [`Complex`] argument by name. `abs` returns
+/// the (real) magnitude and `conj` the conjugate; any other function name
+/// doesn't support complex arguments and returns `None`.
+pub(crate) fn call_unary_complex(name: &str, a: Complex) -> Option<Value> {
+    match name {
+        "abs" => Some(Value::Real(a.magnitude())),
+        "conj" => Some(Value::Complex(a.conjugate())),
+        _ => None,
+    }
+}
+
+/// Call a binary function by name. Returns `None` if the result is outside
+/// the function's domain.
+pub(crate) fn call_binary(name: &str, a: f64, b: f64) -> Option<f64> {
+    let result = match name {
+        "log" => math::log(a, b),
+        "pow" => math::pow(a, b),
+        "min" => math::min(a, b),
+        "max" => math::max(a, b),
+        _ => return None,
+    };
+    (!result.is_nan()).then_some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::evaluate;
+
+    #[test]
+    fn unary_and_binary_dispatch() {
+        assert_eq!(call_unary("sqrt", 4.0), Some(2.0));
+        assert_eq!(call_binary("pow", 2.0, 10.0), Some(1024.0));
+    }
+
+    #[test]
+    fn domain_errors_surface_as_none() {
+        assert_eq!(call_unary("sqrt", -1.0), None);
+    }
+
+    #[test]
+    fn abs_and_conj_are_wired_for_complex_values() {
+        assert_eq!(evaluate("abs(3+4i)").unwrap(), Value::Real(5.0));
+        assert_eq!(
+            evaluate("conj(3+4i)").unwrap(),
+            Value::Complex(Complex::new(3.0, -4.0))
+        );
+        assert_eq!(
+            call_unary_complex("abs", Complex::new(3.0, 4.0)),
+            Some(Value::Real(5.0))
+        );
+    }
+
+    #[test]
+    fn functions_without_complex_support_are_rejected() {
+        assert!(evaluate("sqrt(1+2i)").is_err());
+        assert_eq!(call_unary_complex("sqrt", Complex::new(1.0, 2.0)), None);
+    }
+}
@@ -0,0 +1,233 @@
+use super::error::{Error, ErrorKind};
+use super::span::Span;
+
+fn is_ident_continue(c: Option<&char>) -> bool {
+    matches!(c, Some(c) if c.is_ascii_alphanumeric() || *c == '_')
+}
+
+/// A single lexical token in an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(f64),
+    /// A pure imaginary literal, e.g. `4i` or `2i`; `3+4i` lexes as
+    /// `Number(3.0) Plus Imaginary(4.0)`.
+    Imaginary(f64),
+    Ident(String),
+    /// A `${name:-fallback}` substitution; `fallback` is the raw, unparsed
+    /// source of the fallback expression, evaluated lazily only if `name`
+    /// is unbound.
+    VarDefault { name: String, fallback: Option<String> },
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+    /// Separates arguments inside a function call.
+    Comma,
+    /// Unary negation, e.g. the `-` in `2 * -3`. Never produced by the
+    /// lexer; synthesized by [`super::parser::normalize_unary_minus`] in
+    /// place of a `Minus` that isn't acting as subtraction, so the
+    /// shunting-yard pass can give it its own (high, right-associative)
+    /// precedence instead of the binary `-`'s.
+    Neg,
+    /// A resolved function call, e.g. `sqrt(2)`. Never produced by the
+    /// lexer; synthesized by [`super::parser::to_rpn`] from an `Ident`
+    /// immediately followed by `(`, with `argc` counted as arguments are
+    /// shunted off the stack.
+    Call { name: String, argc: usize },
+}
+
+/// A [`Token`] together with the span of source it was read from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned {
+    pub token: Token,
+    pub span: Span,
+}
+
+/// Split an expression into a flat, spanned stream of tokens, skipping
+/// whitespace.
+pub fn tokenize(input: &str) -> Result<Vec<Spanned>, Error> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        match c {
+            ' ' | '\t' | '\n' | '\r' => {
+                i += 1;
+                continue;
+            }
+            '+' => {
+                tokens.push(Spanned {
+                    token: Token::Plus,
+                    span: Span::new(start, 1),
+                });
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Spanned {
+                    token: Token::Minus,
+                    span: Span::new(start, 1),
+                });
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Spanned {
+                    token: Token::Star,
+                    span: Span::new(start, 1),
+                });
+                i += 1;
+            }
+            '/' => {
+                tokens.push(Spanned {
+                    token: Token::Slash,
+                    span: Span::new(start, 1),
+                });
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Spanned {
+                    token: Token::LParen,
+                    span: Span::new(start, 1),
+                });
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Spanned {
+                    token: Token::RParen,
+                    span: Span::new(start, 1),
+                });
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Spanned {
+                    token: Token::Comma,
+                    span: Span::new(start, 1),
+                });
+                i += 1;
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = text.parse::<f64>().map_err(|_| {
+                    Error::new(ErrorKind::InvalidNumber(text.clone()), Span::new(start, i - start))
+                })?;
+
+                if i < chars.len() && chars[i] == 'i' && !is_ident_continue(chars.get(i + 1)) {
+                    i += 1;
+                    tokens.push(Spanned {
+                        token: Token::Imaginary(value),
+                        span: Span::new(start, i - start),
+                    });
+                } else {
+                    tokens.push(Spanned {
+                        token: Token::Number(value),
+                        span: Span::new(start, i - start),
+                    });
+                }
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let ident: String = chars[start..i].iter().collect();
+                let span = Span::new(start, i - start);
+                if ident == "i" {
+                    tokens.push(Spanned {
+                        token: Token::Imaginary(1.0),
+                        span,
+                    });
+                } else {
+                    tokens.push(Spanned {
+                        token: Token::Ident(ident),
+                        span,
+                    });
+                }
+            }
+            '$' if i + 1 < chars.len() && chars[i + 1] == '{' => {
+                i += 2;
+                let content_start = i;
+                let mut depth = 1;
+                while i < chars.len() && depth > 0 {
+                    match chars[i] {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    if depth > 0 {
+                        i += 1;
+                    }
+                }
+                if depth != 0 {
+                    return Err(Error::new(ErrorKind::UnmatchedBrace, Span::new(start, i - start)));
+                }
+                let content: String = chars[content_start..i].iter().collect();
+                i += 1; // consume the closing '}'
+
+                let (name, fallback) = match content.split_once(":-") {
+                    Some((name, fallback)) => (name.trim().to_string(), Some(fallback.to_string())),
+                    None => (content.trim().to_string(), None),
+                };
+                tokens.push(Spanned {
+                    token: Token::VarDefault { name, fallback },
+                    span: Span::new(start, i - start),
+                });
+            }
+            other => {
+                return Err(Error::new(ErrorKind::UnexpectedCharacter(other), Span::new(start, 1)))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &str) -> Vec<Token> {
+        tokenize(input)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.token)
+            .collect()
+    }
+
+    #[test]
+    fn numbers_operators_and_parens() {
+        assert_eq!(
+            kinds("(1 + 2.5) * 3"),
+            vec![
+                Token::LParen,
+                Token::Number(1.0),
+                Token::Plus,
+                Token::Number(2.5),
+                Token::RParen,
+                Token::Star,
+                Token::Number(3.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_cover_each_token() {
+        let tokens = tokenize("12 + 3").unwrap();
+        assert_eq!(tokens[0].span, Span::new(0, 2));
+        assert_eq!(tokens[1].span, Span::new(3, 1));
+        assert_eq!(tokens[2].span, Span::new(5, 1));
+    }
+
+    #[test]
+    fn unexpected_character_is_an_error() {
+        assert!(matches!(
+            tokenize("1 @ 2").unwrap_err().kind,
+            ErrorKind::UnexpectedCharacter('@')
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+//! A small infix expression engine for the calculator binary.
+//!
+//! Expressions are tokenized, converted to Reverse Polish Notation with the
+//! shunting-yard algorithm, and evaluated over an operand stack using the
+//! functions in [`crate::math`]. Identifiers resolve against a [`Context`]
+//! of built-in constants and user-defined variables. A [`Value`] starts out
+//! real and promotes itself to a [`crate::math::Complex`] automatically as
+//! soon as an imaginary literal (`4i`) enters the computation. Every token
+//! carries a source [`span::Span`], so a failure can be rendered as a
+//! `rustc`-style diagnostic with [`diagnostic::render`]. Calls like
+//! `sqrt(2)` dispatch through the [`functions`] registry.
+
+mod context;
+pub mod diagnostic;
+mod error;
+mod functions;
+mod lexer;
+mod parser;
+pub mod span;
+mod value;
+
+pub use context::Context;
+pub use error::{Error, ErrorKind};
+pub use value::Value;
+
+/// Evaluate a full infix expression such as `"(10 + 5) * 2 / 4 - 1"`.
+///
+/// Supports `+ - * /`, parentheses, a leading/unary minus, and complex
+/// literals like `3+4i`. Returns an error instead of panicking on malformed
+/// input or division by zero. Equivalent to [`evaluate_with_context`] with
+/// an empty [`Context`].
+pub fn evaluate(input: &str) -> Result<Value, Error> {
+    evaluate_with_context(input, &Context::new())
+}
+
+/// Evaluate an expression, resolving identifiers and `${name:-fallback}`
+/// substitutions against `ctx`.
+pub fn evaluate_with_context(input: &str, ctx: &Context) -> Result<Value, Error> {
+    let tokens = lexer::tokenize(input)?;
+    if tokens.is_empty() {
+        return Err(Error::without_span(ErrorKind::EmptyExpression));
+    }
+    let tokens = parser::normalize_unary_minus(tokens);
+    let rpn = parser::to_rpn(&tokens)?;
+    parser::eval_rpn(&rpn, ctx)
+}
@@ -0,0 +1,437 @@
+use crate::math::Complex;
+
+use super::context::Context;
+use super::error::{Error, ErrorKind};
+use super::evaluate_with_context;
+use super::functions::{self, Arity};
+use super::lexer::{Spanned, Token};
+use super::value::Value;
+
+fn precedence(tok: &Token) -> u8 {
+    match tok {
+        Token::Neg => 3,
+        Token::Star | Token::Slash => 2,
+        Token::Plus | Token::Minus => 1,
+        _ => 0,
+    }
+}
+
+fn is_operator(tok: &Token) -> bool {
+    matches!(
+        tok,
+        Token::Plus | Token::Minus | Token::Star | Token::Slash | Token::Neg
+    )
+}
+
+fn is_right_associative(tok: &Token) -> bool {
+    matches!(tok, Token::Neg)
+}
+
+/// Whether `top`, sitting on the operator stack, should be popped to the
+/// output queue before `incoming` is pushed: left-associative operators
+/// pop anything of equal-or-higher precedence, right-associative ones
+/// (just `Neg`, for now) only pop strictly higher precedence, so a chain
+/// like `- -3` nests instead of flattening.
+fn should_pop_before(top: &Token, incoming: &Token) -> bool {
+    is_operator(top)
+        && if is_right_associative(incoming) {
+            precedence(top) > precedence(incoming)
+        } else {
+            precedence(top) >= precedence(incoming)
+        }
+}
+
+fn is_operand(tok: &Token) -> bool {
+    matches!(
+        tok,
+        Token::Number(_) | Token::Imaginary(_) | Token::Ident(_) | Token::VarDefault { .. }
+    )
+}
+
+/// Rewrite a unary minus (leading, after another operator, after `(`, or
+/// after `,`) into a distinct [`Token::Neg`], so the shunting-yard pass can
+/// give it its own high, right-associative precedence instead of treating
+/// it as the binary `-`. `2 * -3` must lex to `2 * (-3)`, not `(2 * 0) - 3`.
+pub(crate) fn normalize_unary_minus(tokens: Vec<Spanned>) -> Vec<Spanned> {
+    let mut out = Vec::with_capacity(tokens.len());
+    let mut prev: Option<&Token> = None;
+    for spanned in &tokens {
+        let is_unary = matches!(spanned.token, Token::Minus)
+            && !matches!(prev, Some(t) if is_operand(t) || matches!(t, Token::RParen));
+        if is_unary {
+            out.push(Spanned {
+                token: Token::Neg,
+                span: spanned.span,
+            });
+        } else {
+            out.push(spanned.clone());
+        }
+        prev = Some(&spanned.token);
+    }
+    out
+}
+
+/// Convert an infix token stream to Reverse Polish Notation using the
+/// shunting-yard algorithm. `*`/`/` bind tighter than `+`/`-`, and all four
+/// operators are left-associative. An `Ident` immediately followed by `(`
+/// is treated as a function call: it's pushed onto the operator stack as a
+/// [`Token::Call`] marker, and each `,` inside its parens bumps the call's
+/// argument count before being discarded (arguments themselves still flow
+/// to the output queue as usual).
+pub(crate) fn to_rpn(tokens: &[Spanned]) -> Result<Vec<Spanned>, Error> {
+    let mut output = Vec::new();
+    let mut stack: Vec<Spanned> = Vec::new();
+
+    for (i, spanned) in tokens.iter().enumerate() {
+        let next_is_lparen = matches!(tokens.get(i + 1).map(|t| &t.token), Some(Token::LParen));
+
+        match &spanned.token {
+            Token::Ident(name) if next_is_lparen => {
+                stack.push(Spanned {
+                    token: Token::Call {
+                        name: name.clone(),
+                        argc: 0,
+                    },
+                    span: spanned.span,
+                });
+            }
+            _ if is_operand(&spanned.token) => output.push(spanned.clone()),
+            Token::Comma => {
+                while let Some(top) = stack.last() {
+                    if matches!(top.token, Token::LParen) {
+                        break;
+                    }
+                    output.push(stack.pop().unwrap());
+                }
+                if stack.is_empty() {
+                    return Err(Error::new(
+                        ErrorKind::InvalidSyntax("unexpected ','".to_string()),
+                        spanned.span,
+                    ));
+                }
+                if let Some(call_idx) = stack.len().checked_sub(2) {
+                    if let Some(Token::Call { argc, .. }) = stack.get_mut(call_idx).map(|s| &mut s.token) {
+                        *argc += 1;
+                    }
+                }
+            }
+            Token::Plus | Token::Minus | Token::Star | Token::Slash | Token::Neg => {
+                while let Some(top) = stack.last() {
+                    if should_pop_before(&top.token, &spanned.token) {
+                        output.push(stack.pop().unwrap());
+                    } else {
+                        break;
+                    }
+                }
+                stack.push(spanned.clone());
+            }
+            Token::LParen => stack.push(spanned.clone()),
+            Token::RParen => {
+                // An empty arg list, `name()`, has no trailing argument to
+                // count — the `(` immediately preceding this `)` is the tell.
+                let empty_args = match i.checked_sub(1) {
+                    Some(prev) => matches!(tokens[prev].token, Token::LParen),
+                    None => false,
+                };
+
+                loop {
+                    match stack.pop() {
+                        Some(top) if matches!(top.token, Token::LParen) => break,
+                        Some(other) => output.push(other),
+                        None => {
+                            return Err(Error::new(ErrorKind::UnmatchedParenthesis, spanned.span))
+                        }
+                    }
+                }
+                if matches!(stack.last().map(|s| &s.token), Some(Token::Call { .. })) {
+                    let mut call = stack.pop().unwrap();
+                    if let Token::Call { argc, .. } = &mut call.token {
+                        if !empty_args {
+                            *argc += 1;
+                        }
+                    }
+                    output.push(call);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    while let Some(top) = stack.pop() {
+        if matches!(top.token, Token::LParen) {
+            return Err(Error::new(ErrorKind::UnmatchedParenthesis, top.span));
+        }
+        output.push(top);
+    }
+
+    Ok(output)
+}
+
+/// Evaluate an RPN token stream with an operand stack. Operators dispatch
+/// through [`Value`], which promotes to [`Complex`] automatically once a
+/// complex operand is involved. Identifiers and `${name:-fallback}`
+/// substitutions are resolved against `ctx`.
+pub(crate) fn eval_rpn(rpn: &[Spanned], ctx: &Context) -> Result<Value, Error> {
+    let mut stack: Vec<Value> = Vec::new();
+
+    for spanned in rpn {
+        match &spanned.token {
+            Token::Number(n) => stack.push(Value::Real(*n)),
+            Token::Imaginary(n) => stack.push(Value::Complex(Complex::new(0.0, *n))),
+            Token::Ident(name) => {
+                let value = ctx.get(name).ok_or_else(|| {
+                    Error::new(ErrorKind::UndefinedVariable(name.clone()), spanned.span)
+                })?;
+                stack.push(value);
+            }
+            Token::VarDefault { name, fallback } => {
+                let value = match ctx.get(name) {
+                    Some(value) => value,
+                    None => match fallback {
+                        Some(fallback) => evaluate_with_context(fallback, ctx)?,
+                        None => {
+                            return Err(Error::new(
+                                ErrorKind::UndefinedVariable(name.clone()),
+                                spanned.span,
+                            ))
+                        }
+                    },
+                };
+                stack.push(value);
+            }
+            Token::Neg => {
+                let a = stack.pop().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidSyntax("missing operand".to_string()),
+                        spanned.span,
+                    )
+                })?;
+                stack.push(a.negate());
+            }
+            Token::Plus | Token::Minus | Token::Star | Token::Slash => {
+                let b = stack.pop().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidSyntax("missing operand".to_string()),
+                        spanned.span,
+                    )
+                })?;
+                let a = stack.pop().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidSyntax("missing operand".to_string()),
+                        spanned.span,
+                    )
+                })?;
+                let result = match &spanned.token {
+                    Token::Plus => a + b,
+                    Token::Minus => a.subtract(b),
+                    Token::Star => a.multiply(b),
+                    Token::Slash => a
+                        .divide(b)
+                        .map_err(|err| Error::new(err.kind, spanned.span))?,
+                    _ => unreachable!(),
+                };
+                stack.push(result);
+            }
+            Token::LParen | Token::RParen | Token::Comma => {
+                return Err(Error::new(
+                    ErrorKind::InvalidSyntax("unexpected parenthesis".to_string()),
+                    spanned.span,
+                ));
+            }
+            Token::Call { name, argc } => {
+                let arity = functions::arity(name)
+                    .ok_or_else(|| Error::new(ErrorKind::UnknownFunction(name.clone()), spanned.span))?;
+                if arity.count() != *argc {
+                    return Err(Error::new(
+                        ErrorKind::ArityMismatch {
+                            name: name.clone(),
+                            expected: arity.count(),
+                            found: *argc,
+                        },
+                        spanned.span,
+                    ));
+                }
+
+                let mut args = Vec::with_capacity(*argc);
+                for _ in 0..*argc {
+                    let value = stack.pop().ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::InvalidSyntax("missing operand".to_string()),
+                            spanned.span,
+                        )
+                    })?;
+                    args.push(value);
+                }
+                args.reverse();
+
+                // A unary function called on a Complex argument routes through
+                // `functions::call_unary_complex` (e.g. `abs`/`conj`) instead
+                // of being flattened to f64; it returns `None` (surfaced as a
+                // DomainError) for any function that doesn't support complex
+                // arguments.
+                if let (Arity::Unary, Value::Complex(c)) = (arity, args[0]) {
+                    let result = functions::call_unary_complex(name, c).ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::DomainError(format!(
+                                "'{}' does not support complex arguments",
+                                name
+                            )),
+                            spanned.span,
+                        )
+                    })?;
+                    stack.push(result);
+                    continue;
+                }
+
+                let mut reals = Vec::with_capacity(args.len());
+                for value in args {
+                    match value {
+                        Value::Real(r) => reals.push(r),
+                        Value::Complex(_) => {
+                            return Err(Error::new(
+                                ErrorKind::DomainError(format!(
+                                    "'{}' does not support complex arguments",
+                                    name
+                                )),
+                                spanned.span,
+                            ))
+                        }
+                    }
+                }
+
+                let result = match arity {
+                    Arity::Unary => functions::call_unary(name, reals[0]),
+                    Arity::Binary => functions::call_binary(name, reals[0], reals[1]),
+                }
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::DomainError(format!(
+                            "'{}' is undefined for the given arguments",
+                            name
+                        )),
+                        spanned.span,
+                    )
+                })?;
+
+                stack.push(Value::Real(result));
+            }
+        }
+    }
+
+    if stack.len() != 1 {
+        let span = rpn.last().map(|s| s.span);
+        let kind = ErrorKind::InvalidSyntax("incomplete expression".to_string());
+        return Err(match span {
+            Some(span) => Error::new(kind, span),
+            None => Error::without_span(kind),
+        });
+    }
+
+    Ok(stack[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::{evaluate, ErrorKind, Value};
+
+    fn real(input: &str) -> f64 {
+        match evaluate(input).unwrap() {
+            Value::Real(r) => r,
+            other => panic!("expected a real value, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn precedence_and_associativity() {
+        assert_eq!(real("2 + 3 * 4"), 14.0);
+        assert_eq!(real("(2 + 3) * 4"), 20.0);
+        assert_eq!(real("10 - 2 - 3"), 5.0);
+        assert_eq!(real("10 / 2 / 5"), 1.0);
+    }
+
+    #[test]
+    fn unary_minus_binds_tighter_than_any_binary_operator() {
+        assert_eq!(real("2 * -3"), -6.0);
+        assert_eq!(real("2 - -3"), 5.0);
+        assert_eq!(real("2 - -3 * 4"), 14.0);
+        assert_eq!(real("10 / -2"), -5.0);
+        assert_eq!(real("8 / -2 + 1"), -3.0);
+        assert_eq!(real("--3"), 3.0);
+        assert_eq!(real("-3 + 4"), 1.0);
+    }
+
+    #[test]
+    fn unmatched_parenthesis_is_an_error() {
+        assert!(matches!(
+            evaluate("(1 + 2").unwrap_err().kind,
+            ErrorKind::UnmatchedParenthesis
+        ));
+        assert!(matches!(
+            evaluate("1 + 2)").unwrap_err().kind,
+            ErrorKind::UnmatchedParenthesis
+        ));
+    }
+
+    #[test]
+    fn division_by_zero_is_an_error() {
+        assert!(matches!(
+            evaluate("1 / 0").unwrap_err().kind,
+            ErrorKind::DivisionByZero
+        ));
+    }
+
+    #[test]
+    fn function_calls_thread_precedence_and_nesting() {
+        assert_eq!(real("pow(2, 3)"), 8.0);
+        assert_eq!(real("sqrt(pow(3, 2) + pow(4, 2))"), 5.0);
+        assert_eq!(real("2 * sqrt(4)"), 4.0);
+    }
+
+    #[test]
+    fn empty_argument_list_is_an_arity_mismatch_not_a_missing_operand() {
+        assert!(matches!(
+            evaluate("sqrt()").unwrap_err().kind,
+            ErrorKind::ArityMismatch {
+                expected: 1,
+                found: 0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn wrong_argument_count_is_an_arity_mismatch() {
+        assert!(matches!(
+            evaluate("sqrt(1, 2)").unwrap_err().kind,
+            ErrorKind::ArityMismatch {
+                expected: 1,
+                found: 2,
+                ..
+            }
+        ));
+        assert!(matches!(
+            evaluate("pow(1)").unwrap_err().kind,
+            ErrorKind::ArityMismatch {
+                expected: 2,
+                found: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn unknown_function_name_is_an_error() {
+        assert!(matches!(
+            evaluate("frobnicate(1)").unwrap_err().kind,
+            ErrorKind::UnknownFunction(name) if name == "frobnicate"
+        ));
+    }
+
+    #[test]
+    fn domain_error_for_sqrt_of_negative_real() {
+        assert!(matches!(
+            evaluate("sqrt(-1)").unwrap_err().kind,
+            ErrorKind::DomainError(_)
+        ));
+    }
+}
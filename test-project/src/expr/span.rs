@@ -0,0 +1,13 @@
+/// A half-open range of character offsets into the original source,
+/// identifying the token responsible for an error so it can be underlined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, len: usize) -> Self {
+        Self { start, len }
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use crate::math;
+use crate::math::Complex;
+
+use super::error::{Error, ErrorKind};
+
+/// A value produced while evaluating an expression.
+///
+/// Expressions start out real; as soon as an imaginary literal (`4i`, `2i`)
+/// appears, that subtree — and anything it combines with — becomes
+/// [`Value::Complex`]. There is no separate "complex mode" flag: the
+/// promotion happens automatically wherever a `Complex` meets a `Real`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Real(f64),
+    Complex(Complex),
+}
+
+impl Value {
+    fn as_complex(self) -> Complex {
+        match self {
+            Value::Real(re) => Complex::new(re, 0.0),
+            Value::Complex(c) => c,
+        }
+    }
+
+    pub fn subtract(self, other: Value) -> Value {
+        match (self, other) {
+            (Value::Real(a), Value::Real(b)) => Value::Real(math::subtract(a, b)),
+            (a, b) => Value::Complex(a.as_complex().subtract(b.as_complex())),
+        }
+    }
+
+    pub fn multiply(self, other: Value) -> Value {
+        match (self, other) {
+            (Value::Real(a), Value::Real(b)) => Value::Real(math::multiply(a, b)),
+            (a, b) => Value::Complex(a.as_complex().multiply(b.as_complex())),
+        }
+    }
+
+    /// Unary negation, e.g. the `-` in `2 * -3`.
+    pub fn negate(self) -> Value {
+        match self {
+            Value::Real(r) => Value::Real(math::subtract(0.0, r)),
+            Value::Complex(c) => Value::Complex(Complex::new(0.0, 0.0).subtract(c)),
+        }
+    }
+
+    pub fn divide(self, other: Value) -> Result<Value, Error> {
+        match (self, other) {
+            (Value::Real(a), Value::Real(b)) => {
+                if b == 0.0 {
+                    return Err(Error::without_span(ErrorKind::DivisionByZero));
+                }
+                Ok(Value::Real(math::divide(a, b)))
+            }
+            (a, b) => a
+                .as_complex()
+                .divide(b.as_complex())
+                .map(Value::Complex)
+                .map_err(|_| Error::without_span(ErrorKind::DivisionByZero)),
+        }
+    }
+}
+
+impl std::ops::Add for Value {
+    type Output = Value;
+
+    fn add(self, other: Value) -> Value {
+        match (self, other) {
+            (Value::Real(a), Value::Real(b)) => Value::Real(math::add(a, b)),
+            (a, b) => Value::Complex(a.as_complex() + b.as_complex()),
+        }
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Real(value)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Real(n) => write!(f, "{}", n),
+            Value::Complex(c) => write!(f, "{}", c),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
+use test_project::expr::{Context, Value};
 use test_project::math;
 
 #[derive(Parser)]
@@ -34,6 +35,8 @@ enum Commands {
         #[arg(short, long, default_value_t = 10)]
         count: usize,
     },
+    /// Start an interactive REPL with persistent variables
+    Repl,
 }
 
 #[derive(Subcommand, Debug)]
@@ -42,6 +45,13 @@ enum MathOps {
     Subtract { a: f64, b: f64 },
     Multiply { a: f64, b: f64 },
     Divide { a: f64, b: f64 },
+    /// Evaluate a free-form expression, e.g. "2 * x + ${y:-1}"
+    Eval {
+        expression: String,
+        /// Bind a variable as NAME=VALUE before evaluating (repeatable)
+        #[arg(long = "set", value_name = "NAME=VALUE")]
+        set: Vec<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -73,17 +83,17 @@ fn main() {
                 MathOps::Add { a, b } => {
                     let result = math::add(*a, *b);
                     println!("{} + {} = {}", a, b, result);
-                    result
+                    Value::Real(result)
                 }
                 MathOps::Subtract { a, b } => {
                     let result = math::subtract(*a, *b);
                     println!("{} - {} = {}", a, b, result);
-                    result
+                    Value::Real(result)
                 }
                 MathOps::Multiply { a, b } => {
                     let result = math::multiply(*a, *b);
                     println!("{} * {} = {}", a, b, result);
-                    result
+                    Value::Real(result)
                 }
                 MathOps::Divide { a, b } => {
                     if *b == 0.0 {
@@ -92,14 +102,39 @@ fn main() {
                     }
                     let result = math::divide(*a, *b);
                     println!("{} / {} = {}", a, b, result);
-                    result
+                    Value::Real(result)
+                }
+                MathOps::Eval { expression, set } => {
+                    let mut ctx = Context::new();
+                    for flag in set {
+                        match Context::parse_binding(flag) {
+                            Ok((name, value)) => ctx.set(name, value),
+                            Err(err) => {
+                                eprintln!("Error: {}", err);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                    match test_project::expr::evaluate_with_context(expression, &ctx) {
+                        Ok(result) => {
+                            println!("{} = {}", expression, result);
+                            result
+                        }
+                        Err(err) => {
+                            eprintln!(
+                                "{}",
+                                test_project::expr::diagnostic::render(expression, &err)
+                            );
+                            std::process::exit(1);
+                        }
+                    }
                 }
             };
             
             #[cfg(feature = "json-output")]
             {
                 let json_result = serde_json::json!({
-                    "result": result,
+                    "result": result.to_string(),
                     "operation": format!("{:?}", operation)
                 });
                 println!("JSON: {}", json_result);
@@ -124,9 +159,11 @@ fn main() {
                 println!("\nJSON output:\n{}", json);
             }
         }
+        Some(Commands::Repl) => {
+            test_project::repl::run();
+        }
         None => {
-            println!("Test project is running successfully!");
-            println!("Use --help for available commands.");
+            test_project::repl::run();
         }
     }
 }
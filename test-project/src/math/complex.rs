@@ -0,0 +1,126 @@
+use std::fmt;
+
+/// A complex number in rectangular form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+/// The divisor in a [`Complex::divide`] had zero magnitude.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DivisionByZero;
+
+impl fmt::Display for DivisionByZero {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "division by zero")
+    }
+}
+
+impl std::error::Error for DivisionByZero {}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    pub fn subtract(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+
+    pub fn multiply(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    /// Divide by `other` using `(a+bi)/(c+di) = ((ac+bd) + (bc-ad)i)/(c²+d²)`.
+    pub fn divide(self, other: Complex) -> Result<Complex, DivisionByZero> {
+        let denom = other.re * other.re + other.im * other.im;
+        if denom == 0.0 {
+            return Err(DivisionByZero);
+        }
+        Ok(Complex::new(
+            (self.re * other.re + self.im * other.im) / denom,
+            (self.im * other.re - self.re * other.im) / denom,
+        ))
+    }
+
+    pub fn magnitude(self) -> f64 {
+        (self.re * self.re + self.im * self.im).sqrt()
+    }
+
+    pub fn conjugate(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl std::ops::Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im == 0.0 {
+            write!(f, "{}", self.re)
+        } else if self.re == 0.0 {
+            write!(f, "{}i", self.im)
+        } else if self.im < 0.0 {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(Complex::new(1.0, 2.0) + Complex::new(3.0, 4.0), Complex::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn test_subtract() {
+        assert_eq!(Complex::new(3.0, 4.0).subtract(Complex::new(1.0, 1.0)), Complex::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_multiply() {
+        // (3+4i)(1+2i) = (3*1 - 4*2) + (3*2 + 4*1)i = -5 + 10i
+        assert_eq!(Complex::new(3.0, 4.0).multiply(Complex::new(1.0, 2.0)), Complex::new(-5.0, 10.0));
+    }
+
+    #[test]
+    fn test_divide() {
+        // (1+2i)/(1+2i) = 1
+        let result = Complex::new(1.0, 2.0).divide(Complex::new(1.0, 2.0)).unwrap();
+        assert_eq!(result, Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_divide_by_zero_magnitude() {
+        assert_eq!(
+            Complex::new(1.0, 2.0).divide(Complex::new(0.0, 0.0)),
+            Err(DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(Complex::new(3.0, 4.0).magnitude(), 5.0);
+    }
+
+    #[test]
+    fn test_conjugate() {
+        assert_eq!(Complex::new(3.0, 4.0).conjugate(), Complex::new(3.0, -4.0));
+        assert_eq!(Complex::new(3.0, -4.0).conjugate(), Complex::new(3.0, 4.0));
+    }
+}
@@ -0,0 +1,115 @@
+pub mod complex;
+
+pub use complex::Complex;
+
+/// Add two numbers
+pub fn add(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+/// Subtract two numbers
+pub fn subtract(a: f64, b: f64) -> f64 {
+    a - b
+}
+
+/// Multiply two numbers
+pub fn multiply(a: f64, b: f64) -> f64 {
+    a * b
+}
+
+/// Divide two numbers
+pub fn divide(a: f64, b: f64) -> f64 {
+    if b == 0.0 {
+        panic!("Division by zero");
+    }
+    a / b
+}
+
+/// Square root
+pub fn sqrt(a: f64) -> f64 {
+    a.sqrt()
+}
+
+/// Absolute value
+pub fn abs(a: f64) -> f64 {
+    a.abs()
+}
+
+/// Sine, in radians
+pub fn sin(a: f64) -> f64 {
+    a.sin()
+}
+
+/// Cosine, in radians
+pub fn cos(a: f64) -> f64 {
+    a.cos()
+}
+
+/// Tangent, in radians
+pub fn tan(a: f64) -> f64 {
+    a.tan()
+}
+
+/// Natural logarithm
+pub fn ln(a: f64) -> f64 {
+    a.ln()
+}
+
+/// Logarithm of `a` in the given `base`
+pub fn log(a: f64, base: f64) -> f64 {
+    a.log(base)
+}
+
+/// Raise `a` to the power `b`
+pub fn pow(a: f64, b: f64) -> f64 {
+    a.powf(b)
+}
+
+/// The smaller of two numbers
+pub fn min(a: f64, b: f64) -> f64 {
+    a.min(b)
+}
+
+/// The larger of two numbers
+pub fn max(a: f64, b: f64) -> f64 {
+    a.max(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(add(2.0, 3.0), 5.0);
+        assert_eq!(add(-1.0, 1.0), 0.0);
+        assert_eq!(add(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_subtract() {
+        assert_eq!(subtract(5.0, 3.0), 2.0);
+        assert_eq!(subtract(0.0, 5.0), -5.0);
+        assert_eq!(subtract(3.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn test_multiply() {
+        assert_eq!(multiply(2.0, 3.0), 6.0);
+        assert_eq!(multiply(-2.0, 3.0), -6.0);
+        assert_eq!(multiply(0.0, 5.0), 0.0);
+    }
+
+    #[test]
+    fn test_divide() {
+        assert_eq!(divide(6.0, 2.0), 3.0);
+        assert_eq!(divide(5.0, 2.0), 2.5);
+        assert_eq!(divide(-6.0, 2.0), -3.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_divide_by_zero() {
+        divide(5.0, 0.0);
+    }
+}
@@ -0,0 +1,148 @@
+//! Interactive read-eval-print loop over the expression engine.
+
+use std::path::PathBuf;
+
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+use crate::expr::{self, Context, Value};
+
+const BANNER: &str = "test-project REPL — enter an expression, `name = expr` to assign, :vars to list variables, :quit to leave";
+
+fn history_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("test-project").join("history.txt"))
+}
+
+/// Run the REPL until EOF, `Ctrl-C`, or `:quit`.
+///
+/// Lines are evaluated with the expression engine from [`crate::expr`],
+/// against a [`Context`] that persists for the whole session. `name = expr`
+/// assigns the result to `name`, which can then be referenced in later
+/// lines. Arrow-key editing and cross-session history are provided by
+/// `rustyline`, persisted to a file under the user's config directory.
+pub fn run() {
+    println!("{}", BANNER);
+
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(err) => {
+            eprintln!("Error: failed to start line editor: {}", err);
+            return;
+        }
+    };
+
+    let history_path = history_path();
+    if let Some(path) = &history_path {
+        let _ = editor.load_history(path);
+    }
+
+    let mut ctx = Context::new();
+
+    loop {
+        match editor.readline("> ") {
+            Ok(line) => {
+                let _ = editor.add_history_entry(line.as_str());
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+
+                match line {
+                    ":quit" => break,
+                    ":vars" => print_vars(&ctx),
+                    _ => handle_line(line, &mut ctx),
+                }
+            }
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                break;
+            }
+        }
+    }
+
+    if let Some(path) = &history_path {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = editor.save_history(path);
+    }
+}
+
+fn print_vars(ctx: &Context) {
+    let mut vars: Vec<(&String, &Value)> = ctx.variables().collect();
+    if vars.is_empty() {
+        println!("(no variables defined)");
+        return;
+    }
+    vars.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, value) in vars {
+        println!("{} = {}", name, value);
+    }
+}
+
+fn handle_line(line: &str, ctx: &mut Context) {
+    if let Some((name, expr_src)) = parse_assignment(line) {
+        match expr::evaluate_with_context(expr_src, ctx) {
+            Ok(value) => {
+                ctx.set(name, value);
+                println!("{} = {}", name, value);
+            }
+            Err(err) => eprintln!("{}", expr::diagnostic::render(expr_src, &err)),
+        }
+        return;
+    }
+
+    match expr::evaluate_with_context(line, ctx) {
+        Ok(value) => println!("{}", value),
+        Err(err) => eprintln!("{}", expr::diagnostic::render(line, &err)),
+    }
+}
+
+/// Split a `name = expression` line in two, or return `None` if `line`
+/// isn't an assignment.
+fn parse_assignment(line: &str) -> Option<(&str, &str)> {
+    let eq_pos = line.find('=')?;
+    let (name_part, rest) = line.split_at(eq_pos);
+    let name = name_part.trim();
+    if !is_identifier(name) {
+        return None;
+    }
+    Some((name, rest[1..].trim()))
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    !s.is_empty() && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_simple_assignment() {
+        assert_eq!(parse_assignment("x = 3 + 4"), Some(("x", "3 + 4")));
+        assert_eq!(parse_assignment("_y=1"), Some(("_y", "1")));
+    }
+
+    #[test]
+    fn rejects_non_assignments() {
+        assert_eq!(parse_assignment("3 + 4"), None);
+        assert_eq!(parse_assignment("1 == 2"), None);
+        assert_eq!(parse_assignment("2x = 1"), None);
+    }
+
+    #[test]
+    fn identifier_rules() {
+        assert!(is_identifier("x"));
+        assert!(is_identifier("_foo1"));
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("1x"));
+        assert!(!is_identifier("x-y"));
+    }
+}